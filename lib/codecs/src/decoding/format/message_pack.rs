@@ -0,0 +1,290 @@
+use bytes::{Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use smallvec::{smallvec, SmallVec};
+use std::io::Cursor;
+use value::Kind;
+use vector_core::config::LogNamespace;
+use vector_core::{
+    config::{log_schema, DataType},
+    event::{Event, LogEvent},
+    schema,
+};
+
+use super::{Deserializer, StreamingDeserializer};
+
+/// Config used to build a `MessagePackDeserializer`.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct MessagePackDeserializerConfig;
+
+impl MessagePackDeserializerConfig {
+    /// Creates a new `MessagePackDeserializerConfig`.
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Build the `MessagePackDeserializer` from this configuration.
+    pub fn build(&self) -> MessagePackDeserializer {
+        MessagePackDeserializer::new()
+    }
+
+    /// Return the type of event build by this deserializer.
+    pub fn output_type(&self) -> DataType {
+        DataType::Log
+    }
+
+    /// The schema produced by the deserializer.
+    pub fn schema_definition(&self, log_namespace: LogNamespace) -> schema::Definition {
+        match log_namespace {
+            LogNamespace::Legacy => schema::Definition::empty_legacy_namespace().with_event_field(
+                &lookup::lookup_v2::parse_value_path(log_schema().message_key())
+                    .expect("valid message key"),
+                Kind::any(),
+                Some("message"),
+            ),
+            LogNamespace::Vector => {
+                schema::Definition::new_with_default_metadata(Kind::any(), [log_namespace])
+            }
+        }
+    }
+}
+
+/// Deserializer that converts MessagePack-encoded bytes to an `Event`.
+#[derive(Debug, Clone, Default)]
+pub struct MessagePackDeserializer;
+
+impl MessagePackDeserializer {
+    /// Creates a new `MessagePackDeserializer`.
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Deserializes the given bytes, which must contain a single MessagePack frame, producing a
+    /// single `LogEvent`.
+    pub fn parse_single(
+        &self,
+        bytes: Bytes,
+        log_namespace: LogNamespace,
+    ) -> vector_common::Result<LogEvent> {
+        let value: value::Value = rmp_serde::from_slice(&bytes)
+            .map_err(|error| format!("error decoding MessagePack frame: {error}"))?;
+
+        Ok(match log_namespace {
+            LogNamespace::Vector => log_namespace.new_log_from_data(value),
+            LogNamespace::Legacy => {
+                let mut log = LogEvent::default();
+
+                match value {
+                    value::Value::Object(fields) => {
+                        for (key, value) in fields {
+                            log.insert(key.as_str(), value);
+                        }
+                    }
+                    scalar => {
+                        log.insert(log_schema().message_key(), scalar);
+                    }
+                }
+
+                log
+            }
+        })
+    }
+}
+
+impl Deserializer for MessagePackDeserializer {
+    fn parse(
+        &self,
+        bytes: Bytes,
+        log_namespace: LogNamespace,
+    ) -> vector_common::Result<SmallVec<[Event; 1]>> {
+        let log = self.parse_single(bytes, log_namespace)?;
+        Ok(smallvec![log.into()])
+    }
+}
+
+/// A [`StreamingDeserializer`] that retains the unparsed tail of its input between calls, so
+/// MessagePack frames concatenated across separate reads (e.g. separate TCP segments) are
+/// still decoded correctly.
+#[derive(Debug, Clone, Default)]
+pub struct MessagePackStreamingDeserializer {
+    inner: MessagePackDeserializer,
+    buffer: BytesMut,
+}
+
+impl MessagePackStreamingDeserializer {
+    /// Creates a new `MessagePackStreamingDeserializer`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Removes and returns the first complete MessagePack value in the buffer, leaving any
+    /// trailing partial value in place.
+    fn take_document(&mut self) -> vector_common::Result<Option<Bytes>> {
+        let mut cursor = Cursor::new(&self.buffer[..]);
+        let mut deserializer = rmp_serde::Deserializer::new(&mut cursor);
+
+        match value::Value::deserialize(&mut deserializer) {
+            Ok(_) => {
+                let consumed = cursor.position() as usize;
+                Ok(Some(self.buffer.split_to(consumed).freeze()))
+            }
+            // Ran out of bytes mid-value; wait for more before treating this as malformed.
+            Err(rmp_serde::decode::Error::InvalidMarkerRead(ref error))
+            | Err(rmp_serde::decode::Error::InvalidDataRead(ref error))
+                if error.kind() == std::io::ErrorKind::UnexpectedEof =>
+            {
+                Ok(None)
+            }
+            Err(error) => Err(format!("error decoding MessagePack frame: {error}").into()),
+        }
+    }
+}
+
+impl StreamingDeserializer for MessagePackStreamingDeserializer {
+    fn parse_stream(
+        &mut self,
+        bytes: &Bytes,
+        log_namespace: LogNamespace,
+    ) -> (SmallVec<[Event; 1]>, Option<vector_common::Error>) {
+        self.buffer.extend_from_slice(bytes);
+
+        let mut events = SmallVec::new();
+
+        loop {
+            let document = match self.take_document() {
+                Ok(Some(document)) => document,
+                Ok(None) => break,
+                Err(error) => return (events, Some(error)),
+            };
+
+            match self.inner.parse_single(document, log_namespace) {
+                Ok(log) => events.push(log.into()),
+                Err(error) => return (events, Some(error)),
+            }
+        }
+
+        (events, None)
+    }
+
+    fn schema_definition(&self, log_namespace: LogNamespace) -> schema::Definition {
+        MessagePackDeserializerConfig::new().schema_definition(log_namespace)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use value::Value;
+    use vector_core::config::log_schema;
+
+    use super::*;
+
+    fn encode(value: &value::Value) -> Bytes {
+        let mut buf = Vec::new();
+        value.serialize(&mut rmp_serde::Serializer::new(&mut buf)).unwrap();
+        Bytes::from(buf)
+    }
+
+    #[test]
+    fn deserialize_message_pack_legacy_namespace() {
+        let mut fields = std::collections::BTreeMap::new();
+        fields.insert("foo".to_string(), Value::from("bar"));
+        let input = encode(&Value::Object(fields));
+
+        let deserializer = MessagePackDeserializer::new();
+        let events = deserializer.parse(input, LogNamespace::Legacy).unwrap();
+        let mut events = events.into_iter();
+
+        let event = events.next().unwrap();
+        let log = event.as_log();
+        assert_eq!(log["foo"], "bar".into());
+
+        assert_eq!(events.next(), None);
+    }
+
+    #[test]
+    fn deserialize_message_pack_legacy_namespace_scalar_root() {
+        let input = encode(&Value::from("bare string"));
+
+        let deserializer = MessagePackDeserializer::new();
+        let events = deserializer.parse(input, LogNamespace::Legacy).unwrap();
+        let log = events[0].as_log();
+
+        assert_eq!(log[log_schema().message_key()], "bare string".into());
+    }
+
+    #[test]
+    fn deserialize_message_pack_vector_namespace() {
+        let mut fields = std::collections::BTreeMap::new();
+        fields.insert("foo".to_string(), Value::from("bar"));
+        let input = encode(&Value::Object(fields));
+
+        let deserializer = MessagePackDeserializer::new();
+        let events = deserializer.parse(input, LogNamespace::Vector).unwrap();
+        let log = events[0].as_log();
+
+        assert_eq!(log.get(".foo").unwrap(), &Value::from("bar"));
+    }
+
+    #[test]
+    fn parse_stream_buffers_frames_split_across_chunks() {
+        let mut fields = std::collections::BTreeMap::new();
+        fields.insert("foo".to_string(), Value::from("bar"));
+        let encoded = encode(&Value::Object(fields));
+
+        let split_at = encoded.len() / 2;
+        let first_chunk = encoded.slice(..split_at);
+        let second_chunk = encoded.slice(split_at..);
+
+        let mut deserializer = MessagePackStreamingDeserializer::new();
+
+        let (events, error) = deserializer.parse_stream(&first_chunk, LogNamespace::Vector);
+        assert!(error.is_none());
+        assert_eq!(events.len(), 0);
+
+        let (events, error) = deserializer.parse_stream(&second_chunk, LogNamespace::Vector);
+        assert!(error.is_none());
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].as_log().get(".foo").unwrap(), &Value::from("bar"));
+    }
+
+    #[test]
+    fn parse_stream_emits_multiple_concatenated_frames() {
+        let first = encode(&Value::from("one"));
+        let second = encode(&Value::from("two"));
+        let mut concatenated = first.to_vec();
+        concatenated.extend_from_slice(&second);
+
+        let mut deserializer = MessagePackStreamingDeserializer::new();
+        let (events, error) =
+            deserializer.parse_stream(&Bytes::from(concatenated), LogNamespace::Legacy);
+
+        assert!(error.is_none());
+        assert_eq!(events.len(), 2);
+        assert_eq!(
+            events[0].as_log()[log_schema().message_key()],
+            "one".into()
+        );
+        assert_eq!(
+            events[1].as_log()[log_schema().message_key()],
+            "two".into()
+        );
+    }
+
+    #[test]
+    fn parse_stream_keeps_valid_events_preceding_a_malformed_frame() {
+        let valid = encode(&Value::from("one"));
+        let mut input = valid.to_vec();
+        // A complete (not truncated) str8 frame declaring one byte of payload that is not
+        // valid UTF-8, which rmp_serde rejects as a genuine decode error rather than EOF.
+        input.extend_from_slice(&[0xd9, 0x01, 0xff]);
+
+        let mut deserializer = MessagePackStreamingDeserializer::new();
+        let (events, error) = deserializer.parse_stream(&Bytes::from(input), LogNamespace::Legacy);
+
+        assert!(error.is_some());
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            events[0].as_log()[log_schema().message_key()],
+            "one".into()
+        );
+    }
+}
@@ -0,0 +1,61 @@
+//! A collection of formats that can be used to convert from bytes to events.
+
+mod bytes;
+mod message_pack;
+mod xml;
+
+use ::bytes::Bytes;
+use dyn_clone::DynClone;
+use smallvec::SmallVec;
+use vector_core::{config::LogNamespace, event::Event, schema};
+
+pub use self::bytes::{BytesDeserializer, BytesDeserializerConfig};
+pub use message_pack::{
+    MessagePackDeserializer, MessagePackDeserializerConfig, MessagePackStreamingDeserializer,
+};
+pub use xml::{
+    XmlDeserializer, XmlDeserializerConfig, XmlDeserializerOptions, XmlStreamingDeserializer,
+};
+
+/// Parse structured events from bytes.
+pub trait Deserializer: DynClone + Send + Sync + std::fmt::Debug {
+    /// Parses structured events from bytes.
+    ///
+    /// It returns a `SmallVec` rather than an `Event` directly, since one byte frame can
+    /// potentially hold multiple events, e.g. when parsing a JSON array.
+    fn parse(
+        &self,
+        bytes: Bytes,
+        log_namespace: LogNamespace,
+    ) -> vector_common::Result<SmallVec<[Event; 1]>>;
+}
+
+dyn_clone::clone_trait_object!(Deserializer);
+
+/// Parse structured events from a byte stream whose document boundaries don't necessarily
+/// line up with the chunks handed to each call.
+///
+/// Unlike [`Deserializer`], which requires each call to `parse` to contain exactly one (or more)
+/// complete documents, a `StreamingDeserializer` retains any unparsed trailing bytes between
+/// calls. This lets it decode formats where a single logical document can be split across
+/// multiple reads, such as multi-document XML or concatenated binary payloads, without requiring
+/// the upstream framer to align frame boundaries with document boundaries.
+pub trait StreamingDeserializer: std::fmt::Debug {
+    /// Appends `bytes` to this deserializer's internal buffer and parses as many complete
+    /// documents out of it as possible.
+    ///
+    /// Any unparsed trailing partial document is retained internally and prefixed onto the
+    /// buffer on the next call to `parse_stream`.
+    ///
+    /// The events successfully decoded before a malformed document was hit are always
+    /// returned alongside the error, rather than being discarded: a single corrupt document
+    /// must not cause the valid documents that preceded it in the same chunk to be lost.
+    fn parse_stream(
+        &mut self,
+        bytes: &Bytes,
+        log_namespace: LogNamespace,
+    ) -> (SmallVec<[Event; 1]>, Option<vector_common::Error>);
+
+    /// The schema produced by the deserializer.
+    fn schema_definition(&self, log_namespace: LogNamespace) -> schema::Definition;
+}
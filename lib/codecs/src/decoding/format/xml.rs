@@ -0,0 +1,430 @@
+use bytes::{Bytes, BytesMut};
+use lookup::lookup_v2::parse_value_path;
+use lookup::OwnedTargetPath;
+use quick_xml::events::Event as XmlEvent;
+use quick_xml::Reader;
+use serde::{Deserialize, Serialize};
+use smallvec::{smallvec, SmallVec};
+use std::collections::BTreeMap;
+use value::{kind::Collection, Kind, Value};
+use vector_core::config::LogNamespace;
+use vector_core::{
+    config::{log_schema, DataType},
+    event::{Event, LogEvent},
+    schema,
+};
+
+use super::{Deserializer, StreamingDeserializer};
+
+/// Config used to build a `XmlDeserializer`.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct XmlDeserializerConfig {
+    /// XML-specific decoding options.
+    #[serde(default, skip_serializing_if = "vector_core::serde::is_default")]
+    pub xml: XmlDeserializerOptions,
+}
+
+impl XmlDeserializerConfig {
+    /// Creates a new `XmlDeserializerConfig`.
+    pub const fn new(options: XmlDeserializerOptions) -> Self {
+        Self { xml: options }
+    }
+
+    /// Build the `XmlDeserializer` from this configuration.
+    pub fn build(&self) -> XmlDeserializer {
+        XmlDeserializer::new(self.xml.clone())
+    }
+
+    /// Return the type of event build by this deserializer.
+    pub fn output_type(&self) -> DataType {
+        DataType::Log
+    }
+
+    /// The schema produced by the deserializer.
+    pub fn schema_definition(&self, log_namespace: LogNamespace) -> schema::Definition {
+        let kind = Kind::object(Collection::any());
+
+        match log_namespace {
+            LogNamespace::Legacy => schema::Definition::empty_legacy_namespace().with_event_field(
+                &parse_value_path(log_schema().message_key()).expect("valid message key"),
+                kind,
+                Some("message"),
+            ),
+            LogNamespace::Vector => {
+                schema::Definition::new_with_default_metadata(kind, [log_namespace])
+            }
+        }
+    }
+}
+
+/// Options for building a `XmlDeserializer`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct XmlDeserializerOptions {
+    /// Field under which the text content of an element is stored.
+    ///
+    /// An element's text is always nested under this key on the element's own object, even
+    /// when the element has no attributes or child elements.
+    pub text_key: String,
+
+    /// Prefix applied to attribute field names, to distinguish them from child elements
+    /// that may share the same name.
+    pub attr_prefix: String,
+
+    /// Whether to strip XML namespace prefixes (the part before `:`) from element and
+    /// attribute names.
+    pub strip_namespace: bool,
+}
+
+impl Default for XmlDeserializerOptions {
+    fn default() -> Self {
+        Self {
+            text_key: "#text".to_string(),
+            attr_prefix: "@".to_string(),
+            strip_namespace: false,
+        }
+    }
+}
+
+/// Deserializer that converts a byte frame containing an XML document into an `Event`.
+#[derive(Debug, Clone)]
+pub struct XmlDeserializer {
+    options: XmlDeserializerOptions,
+}
+
+impl Default for XmlDeserializer {
+    fn default() -> Self {
+        Self::new(XmlDeserializerOptions::default())
+    }
+}
+
+impl XmlDeserializer {
+    /// Creates a new `XmlDeserializer`.
+    pub const fn new(options: XmlDeserializerOptions) -> Self {
+        Self { options }
+    }
+
+    /// Deserializes the given bytes, which must contain a single XML document, producing a
+    /// single `LogEvent`.
+    pub fn parse_single(
+        &self,
+        bytes: Bytes,
+        log_namespace: LogNamespace,
+    ) -> vector_common::Result<LogEvent> {
+        let root = self.parse_xml(&bytes)?;
+
+        Ok(match log_namespace {
+            LogNamespace::Vector => log_namespace.new_log_from_data(root),
+            LogNamespace::Legacy => {
+                let mut log = LogEvent::default();
+                log.insert(log_schema().message_key(), root);
+                log
+            }
+        })
+    }
+
+    fn parse_xml(&self, bytes: &[u8]) -> vector_common::Result<Value> {
+        let mut reader = Reader::from_reader(bytes);
+        reader.trim_text(true);
+        reader.expand_empty_elements(true);
+
+        let mut buf = Vec::new();
+        let mut stack: Vec<(String, BTreeMap<String, Value>)> = Vec::new();
+        let mut root: Option<Value> = None;
+
+        loop {
+            match reader.read_event(&mut buf)? {
+                XmlEvent::Start(ref start) => {
+                    let name = self.local_name(start.name());
+                    let mut object = BTreeMap::new();
+
+                    for attr in start.attributes() {
+                        let attr = attr?;
+                        let key = self.local_name(attr.key);
+                        let value = attr.unescape_and_decode_value(&reader)?;
+                        object.insert(format!("{}{}", self.options.attr_prefix, key), value.into());
+                    }
+
+                    stack.push((name, object));
+                }
+                XmlEvent::Text(text) => {
+                    let text = text.unescape_and_decode(&reader)?;
+                    self.insert_text(&mut stack, text);
+                }
+                XmlEvent::CData(cdata) => {
+                    let text = cdata.unescape_and_decode(&reader)?;
+                    self.insert_text(&mut stack, text);
+                }
+                XmlEvent::End(_) => {
+                    let (name, object) = stack
+                        .pop()
+                        .ok_or("closing tag found without a matching opening tag")?;
+                    let value = Value::Object(object);
+
+                    match stack.last_mut() {
+                        Some((_, parent)) => insert_field(parent, name, value),
+                        None => root = Some(value),
+                    }
+                }
+                XmlEvent::Eof => break,
+                _ => {}
+            }
+
+            buf.clear();
+        }
+
+        root.ok_or_else(|| "no root element found in XML document".into())
+    }
+
+    fn insert_text(&self, stack: &mut [(String, BTreeMap<String, Value>)], text: String) {
+        if text.is_empty() {
+            return;
+        }
+
+        if let Some((_, object)) = stack.last_mut() {
+            object
+                .entry(self.options.text_key.clone())
+                .and_modify(|existing| {
+                    let mut combined = existing.to_string_lossy().into_owned();
+                    combined.push_str(&text);
+                    *existing = combined.into();
+                })
+                .or_insert_with(|| text.into());
+        }
+    }
+
+    fn local_name(&self, name: &[u8]) -> String {
+        let name = String::from_utf8_lossy(name).into_owned();
+
+        if self.options.strip_namespace {
+            name.rsplit(':').next().unwrap_or(&name).to_string()
+        } else {
+            name
+        }
+    }
+}
+
+/// Promotes a field to an array if it is set more than once, mirroring how repeated
+/// XML child elements are represented.
+fn insert_field(object: &mut BTreeMap<String, Value>, key: String, value: Value) {
+    match object.remove(&key) {
+        Some(Value::Array(mut values)) => {
+            values.push(value);
+            object.insert(key, Value::Array(values));
+        }
+        Some(existing) => {
+            object.insert(key, Value::Array(vec![existing, value]));
+        }
+        None => {
+            object.insert(key, value);
+        }
+    }
+}
+
+impl Deserializer for XmlDeserializer {
+    fn parse(
+        &self,
+        bytes: Bytes,
+        log_namespace: LogNamespace,
+    ) -> vector_common::Result<SmallVec<[Event; 1]>> {
+        let log = self.parse_single(bytes, log_namespace)?;
+        Ok(smallvec![log.into()])
+    }
+}
+
+/// A [`StreamingDeserializer`] that retains the unparsed tail of its input between calls,
+/// so multiple XML documents concatenated across separate reads (e.g. separate TCP
+/// segments) are still decoded correctly.
+#[derive(Debug, Clone)]
+pub struct XmlStreamingDeserializer {
+    inner: XmlDeserializer,
+    buffer: BytesMut,
+}
+
+impl XmlStreamingDeserializer {
+    /// Creates a new `XmlStreamingDeserializer`.
+    pub fn new(options: XmlDeserializerOptions) -> Self {
+        Self {
+            inner: XmlDeserializer::new(options),
+            buffer: BytesMut::new(),
+        }
+    }
+
+    /// Removes and returns the first complete XML document in the buffer, leaving any
+    /// trailing partial document in place.
+    fn take_document(&mut self) -> vector_common::Result<Option<Bytes>> {
+        let mut reader = Reader::from_reader(&self.buffer[..]);
+        reader.trim_text(true);
+        reader.expand_empty_elements(true);
+
+        let mut buf = Vec::new();
+        let mut depth = 0u32;
+        let mut started = false;
+
+        loop {
+            match reader.read_event(&mut buf) {
+                Ok(XmlEvent::Start(_)) => {
+                    started = true;
+                    depth += 1;
+                }
+                Ok(XmlEvent::End(_)) => {
+                    depth -= 1;
+
+                    if started && depth == 0 {
+                        let document = self.buffer.split_to(reader.buffer_position()).freeze();
+                        return Ok(Some(document));
+                    }
+                }
+                Ok(XmlEvent::Eof) => return Ok(None),
+                Ok(_) => {}
+                // quick_xml surfaces a truncated tag/entity at the end of the buffer as
+                // `UnexpectedEof`; that just means we haven't received the rest of the
+                // document yet. Any other error is a genuinely malformed document (bad
+                // entity, mismatched end tag, invalid UTF-8, ...) and must be propagated so
+                // the caller can drop/reset the stream instead of buffering it forever.
+                Err(quick_xml::Error::UnexpectedEof(_)) => return Ok(None),
+                Err(error) => return Err(error.into()),
+            }
+
+            buf.clear();
+        }
+    }
+}
+
+impl StreamingDeserializer for XmlStreamingDeserializer {
+    fn parse_stream(
+        &mut self,
+        bytes: &Bytes,
+        log_namespace: LogNamespace,
+    ) -> (SmallVec<[Event; 1]>, Option<vector_common::Error>) {
+        self.buffer.extend_from_slice(bytes);
+
+        let mut events = SmallVec::new();
+
+        loop {
+            let document = match self.take_document() {
+                Ok(Some(document)) => document,
+                Ok(None) => break,
+                Err(error) => return (events, Some(error)),
+            };
+
+            match self.inner.parse_single(document, log_namespace) {
+                Ok(log) => events.push(log.into()),
+                Err(error) => return (events, Some(error)),
+            }
+        }
+
+        (events, None)
+    }
+
+    fn schema_definition(&self, log_namespace: LogNamespace) -> schema::Definition {
+        XmlDeserializerConfig::new(self.inner.options.clone()).schema_definition(log_namespace)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vector_core::config::log_schema;
+
+    use super::*;
+
+    #[test]
+    fn deserialize_xml_legacy_namespace() {
+        let input = Bytes::from(r#"<log id="1"><message>hello</message></log>"#);
+        let deserializer = XmlDeserializer::default();
+
+        let events = deserializer.parse(input, LogNamespace::Legacy).unwrap();
+        let mut events = events.into_iter();
+
+        let event = events.next().unwrap();
+        let log = event.as_log();
+        let root = log[log_schema().message_key()].as_object().unwrap();
+
+        assert_eq!(root["@id"], "1".into());
+        assert_eq!(root["message"]["#text"], "hello".into());
+
+        assert_eq!(events.next(), None);
+    }
+
+    #[test]
+    fn deserialize_xml_repeated_elements_become_array() {
+        let input = Bytes::from(r#"<log><item>a</item><item>b</item></log>"#);
+        let deserializer = XmlDeserializer::default();
+
+        let events = deserializer.parse(input, LogNamespace::Vector).unwrap();
+        let log = events[0].as_log();
+        let root = log.get(".").unwrap().as_object().unwrap();
+        let items = root["item"].as_array().unwrap();
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0]["#text"], "a".into());
+        assert_eq!(items[1]["#text"], "b".into());
+    }
+
+    #[test]
+    fn deserialize_xml_strips_namespace_prefix() {
+        let input = Bytes::from(r#"<ns:log xmlns:ns="urn:example"><ns:msg>hi</ns:msg></ns:log>"#);
+        let options = XmlDeserializerOptions {
+            strip_namespace: true,
+            ..Default::default()
+        };
+        let deserializer = XmlDeserializer::new(options);
+
+        let events = deserializer.parse(input, LogNamespace::Vector).unwrap();
+        let log = events[0].as_log();
+        let root = log.get(".").unwrap().as_object().unwrap();
+
+        assert_eq!(root["msg"]["#text"], "hi".into());
+    }
+
+    #[test]
+    fn parse_stream_buffers_documents_split_across_chunks() {
+        let mut deserializer = XmlStreamingDeserializer::new(XmlDeserializerOptions::default());
+
+        let first_chunk = Bytes::from(r#"<log><a>1</a></log><log><a"#);
+        let second_chunk = Bytes::from(r#">2</a></log>"#);
+
+        let (events, error) = deserializer.parse_stream(&first_chunk, LogNamespace::Vector);
+        assert!(error.is_none());
+        assert_eq!(events.len(), 1);
+
+        let (events, error) = deserializer.parse_stream(&second_chunk, LogNamespace::Vector);
+        assert!(error.is_none());
+        assert_eq!(events.len(), 1);
+
+        let root = events[0].as_log().get(".").unwrap().as_object().unwrap();
+        assert_eq!(root["a"]["#text"], "2".into());
+    }
+
+    #[test]
+    fn parse_stream_errors_on_malformed_document_instead_of_buffering_forever() {
+        let mut deserializer = XmlStreamingDeserializer::new(XmlDeserializerOptions::default());
+
+        let malformed = Bytes::from(r#"<log><a>1</a></bogus>"#);
+        let (events, error) = deserializer.parse_stream(&malformed, LogNamespace::Vector);
+        assert!(events.is_empty());
+        assert!(error.is_some());
+    }
+
+    #[test]
+    fn parse_stream_keeps_valid_events_preceding_a_malformed_document() {
+        let mut deserializer = XmlStreamingDeserializer::new(XmlDeserializerOptions::default());
+
+        // A well-formed document followed, in the same chunk, by a malformed one.
+        let input = Bytes::from(r#"<log><a>1</a></log><log><a>2</a></bogus>"#);
+        let (events, error) = deserializer.parse_stream(&input, LogNamespace::Vector);
+
+        assert!(error.is_some());
+        assert_eq!(events.len(), 1);
+        let root = events[0].as_log().get(".").unwrap().as_object().unwrap();
+        assert_eq!(root["a"]["#text"], "1".into());
+    }
+
+    #[test]
+    fn parse_single_errors_on_malformed_attribute_instead_of_dropping_it() {
+        let input = Bytes::from(r#"<log a="&bogus;">hi</log>"#);
+        let deserializer = XmlDeserializer::default();
+
+        assert!(deserializer.parse(input, LogNamespace::Vector).is_err());
+    }
+}
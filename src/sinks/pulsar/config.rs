@@ -0,0 +1,86 @@
+use vector_config::configurable_component;
+
+use crate::{codecs::EncodingConfig, sinks::util::Compression, template::Template};
+
+/// Configuration for the `pulsar` sink.
+#[configurable_component(sink("pulsar", "Publish observability data to Apache Pulsar."))]
+#[derive(Clone, Debug)]
+pub struct PulsarSinkConfig {
+    /// The endpoint to which the Pulsar client should connect to.
+    #[configurable(metadata(docs::examples = "pulsar://127.0.0.1:6650"))]
+    pub endpoint: String,
+
+    /// The Pulsar topic name to write events to.
+    pub topic: String,
+
+    /// The log field name to use for the Pulsar properties key.
+    ///
+    /// If omitted, no properties will be written.
+    pub properties_key: Option<String>,
+
+    /// A template string to use to populate the Pulsar message's partition/ordering key.
+    ///
+    /// All events that resolve to the same partition key are routed to the same partition,
+    /// guaranteeing ordered delivery for related events, e.g. all records for a given
+    /// customer id.
+    ///
+    /// If omitted, Pulsar falls back to its default (round-robin) routing.
+    #[configurable(metadata(docs::templateable))]
+    pub partition_key: Option<Template>,
+
+    #[configurable(derived)]
+    pub encoding: EncodingConfig,
+
+    #[configurable(derived)]
+    pub compression: Option<Compression>,
+
+    #[configurable(derived)]
+    pub auth: Option<AuthConfig>,
+}
+
+/// Authentication configuration for the `pulsar` sink.
+#[configurable_component]
+#[derive(Clone, Debug)]
+pub struct AuthConfig {
+    /// The basic authentication name/username.
+    pub name: Option<String>,
+
+    /// The basic authentication password.
+    pub token: Option<String>,
+
+    /// The OAuth2 configuration.
+    #[configurable(derived)]
+    pub oauth2: Option<OAuth2Config>,
+}
+
+/// OAuth2-specific authentication configuration.
+#[configurable_component]
+#[derive(Clone, Debug)]
+pub struct OAuth2Config {
+    /// The issuer URL.
+    pub issuer_url: String,
+
+    /// The credentials URL.
+    pub credentials_url: String,
+
+    /// The OAuth2 audience.
+    pub audience: Option<String>,
+
+    /// The OAuth2 scope.
+    pub scope: Option<String>,
+}
+
+impl vector_config::component::GenerateConfig for PulsarSinkConfig {
+    fn generate_config() -> toml::Value {
+        toml::Value::try_from(Self {
+            endpoint: "pulsar://127.0.0.1:6650".to_string(),
+            topic: "topic".to_string(),
+            properties_key: None,
+            partition_key: None,
+            encoding: EncodingConfig::default(),
+            compression: None,
+            auth: None,
+        })
+        .unwrap()
+    }
+}
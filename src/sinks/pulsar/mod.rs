@@ -0,0 +1,13 @@
+//! The Pulsar sink.
+//!
+//! Sends events to [Apache Pulsar](https://pulsar.apache.org/) via its producer API.
+
+mod config;
+mod sink;
+mod util;
+
+pub use config::{AuthConfig, OAuth2Config, PulsarSinkConfig};
+pub use sink::build_message;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,27 @@
+use pulsar::producer::{Message, MessageBuilder};
+
+use super::config::PulsarSinkConfig;
+use super::util::{get_partition_key, get_properties};
+use crate::event::Event;
+
+/// Builds the outgoing Pulsar message for a single event.
+///
+/// This is the call site that actually turns the `properties_key` and `partition_key` sink
+/// options into values that reach the Pulsar producer: [`get_properties`] and
+/// [`get_partition_key`] are resolved from the event here and merged onto the `MessageBuilder`
+/// before the request is handed off for sending.
+pub fn build_message(payload: Vec<u8>, event: &Event, config: &PulsarSinkConfig) -> Message {
+    let mut builder = MessageBuilder::new().with_content(payload);
+
+    if let Some(properties) = get_properties(event, &config.properties_key) {
+        for (key, value) in properties {
+            builder = builder.with_property(key, value);
+        }
+    }
+
+    if let Some(partition_key) = get_partition_key(event, &config.partition_key) {
+        builder = builder.partition_key(partition_key);
+    }
+
+    builder.build()
+}
@@ -28,3 +28,48 @@ fn pulsar_get_headers() {
     assert_eq!(properties.get("a-key").unwrap(), "a-value".as_bytes());
     assert_eq!(properties.get("b-key").unwrap(), "b-value".as_bytes());
 }
+
+#[test]
+fn pulsar_get_partition_key() {
+    let mut event = Event::Log(LogEvent::from("hello"));
+    event.as_mut_log().insert("customer_id", "abc-123");
+
+    let partition_key = crate::template::Template::try_from("{{ customer_id }}").unwrap();
+    let key = super::util::get_partition_key(&event, &Some(partition_key)).unwrap();
+    assert_eq!(key, "abc-123");
+}
+
+#[test]
+fn pulsar_get_partition_key_none_when_unset() {
+    let event = Event::Log(LogEvent::from("hello"));
+    assert_eq!(super::util::get_partition_key(&event, &None), None);
+}
+
+#[test]
+fn pulsar_build_message_carries_partition_key_and_properties() {
+    let properties_key = "properties";
+    let mut property_values = BTreeMap::new();
+    property_values.insert("a-key".to_string(), Value::Bytes(Bytes::from("a-value")));
+
+    let mut event = Event::Log(LogEvent::from("hello"));
+    event.as_mut_log().insert(properties_key, property_values);
+    event.as_mut_log().insert("customer_id", "abc-123");
+
+    let config = PulsarSinkConfig {
+        endpoint: "pulsar://127.0.0.1:6650".to_string(),
+        topic: "topic".to_string(),
+        properties_key: Some(properties_key.to_string()),
+        partition_key: Some(crate::template::Template::try_from("{{ customer_id }}").unwrap()),
+        encoding: Default::default(),
+        compression: None,
+        auth: None,
+    };
+
+    let message = super::sink::build_message(b"payload".to_vec(), &event, &config);
+
+    assert_eq!(message.partition_key.as_deref(), Some("abc-123"));
+    assert_eq!(
+        message.properties.get("a-key").map(Vec::as_slice),
+        Some("a-value".as_bytes())
+    );
+}
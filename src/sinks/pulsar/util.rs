@@ -0,0 +1,48 @@
+use std::collections::HashMap;
+
+use crate::event::Event;
+use crate::template::Template;
+
+/// Builds the Pulsar message properties map from the configured `properties_key`, lifting the
+/// object stored under that field onto the outgoing message.
+pub fn get_properties(
+    event: &Event,
+    properties_key: &Option<String>,
+) -> Option<HashMap<String, Vec<u8>>> {
+    if let Some(properties_key) = properties_key {
+        if let Some(properties) = event.as_log().get(properties_key.as_str()) {
+            if let value::Value::Object(properties) = properties {
+                let mut property_map = HashMap::new();
+                for (key, value) in properties {
+                    property_map.insert(key.to_string(), value.coerce_to_bytes().to_vec());
+                }
+                return Some(property_map);
+            } else {
+                warn!("Pulsar `properties_key` value is not a map.");
+            }
+        }
+    }
+    None
+}
+
+/// Resolves the Pulsar message's partition key from the configured `partition_key` template,
+/// so related events (e.g. all records for a given customer id) can be routed to the same
+/// partition for ordered delivery.
+///
+/// Pulsar's `partition_key` is a UTF-8 string field (distinct from the byte-oriented
+/// `ordering_key` used for key-shared subscriptions), so the rendered template is returned
+/// as-is rather than converted to raw bytes.
+pub fn get_partition_key(event: &Event, partition_key: &Option<Template>) -> Option<String> {
+    let partition_key = partition_key.as_ref()?;
+
+    match partition_key.render_string(event) {
+        Ok(key) => Some(key),
+        Err(error) => {
+            warn!(
+                message = "Failed to render `partition_key` template; dropping partition key for this event.",
+                %error,
+            );
+            None
+        }
+    }
+}